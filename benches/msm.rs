@@ -0,0 +1,51 @@
+//! Benchmarks `parallel::msm`'s windowed Pippenger implementation against
+//! the naive per-base scalar multiplication loop it replaced, at a few
+//! sizes spanning small (`dim`/`read`-sized) and large (`final_cts`-sized)
+//! commitments, generic over the curve the same way the `LTVM` e2e tests
+//! are.
+
+use ark_ec::CurveGroup;
+use ark_std::{test_rng, UniformRand};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use jolt::utils::parallel::msm;
+
+/// The straight-line loop `msm` replaced for `DensifiedRepresentation::commit`:
+/// one scalar multiplication per base, summed directly.
+fn naive_msm<G: CurveGroup>(bases: &[G::Affine], scalars: &[G::ScalarField]) -> G {
+    bases
+        .iter()
+        .zip(scalars.iter())
+        .map(|(base, scalar)| *base * scalar)
+        .sum()
+}
+
+fn bench_msm<G: CurveGroup>(c: &mut Criterion, group_name: &str) {
+    let mut rng = test_rng();
+    let mut group = c.benchmark_group(group_name);
+
+    for size in [1usize << 8, 1 << 12, 1 << 16] {
+        let bases: Vec<G::Affine> = (0..size).map(|_| G::rand(&mut rng).into()).collect();
+        let scalars: Vec<G::ScalarField> = (0..size).map(|_| G::ScalarField::rand(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("naive", size), &size, |b, _| {
+            b.iter(|| naive_msm::<G>(black_box(&bases), black_box(&scalars)))
+        });
+        group.bench_with_input(BenchmarkId::new("pippenger", size), &size, |b, _| {
+            b.iter(|| msm::<G>(black_box(&bases), black_box(&scalars)))
+        });
+    }
+
+    group.finish();
+}
+
+fn msm_curve25519(c: &mut Criterion) {
+    bench_msm::<ark_curve25519::EdwardsProjective>(c, "msm/curve25519");
+}
+
+fn msm_bn254(c: &mut Criterion) {
+    bench_msm::<ark_bn254::G1Projective>(c, "msm/bn254");
+}
+
+criterion_group!(benches, msm_curve25519, msm_bn254);
+criterion_main!(benches);
@@ -0,0 +1,3 @@
+pub mod instruction;
+pub mod subtable;
+pub mod vm;
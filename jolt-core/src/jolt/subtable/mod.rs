@@ -0,0 +1,21 @@
+use ark_ff::PrimeField;
+use std::fmt::Debug;
+
+/// A single Lasso subtable: a materializable table together with its
+/// multilinear extension, shared by every `JoltInstruction` that reads from
+/// it (e.g. `beq`/`bne`/`slt` all touch `EQ`/`LT`).
+///
+/// `subtable_id` identifies the subtable's *type*, independent of any
+/// particular instance, so a batched proof over a full trace can group
+/// lookups by shared subtable and materialize each one only once.
+pub trait LassoSubtable<F: PrimeField>: Debug + Send + Sync {
+    /// Size `M` of the table.
+    fn memory_size(&self) -> usize;
+    /// Materializes the table in counting order.
+    fn materialize(&self) -> Vec<F>;
+    /// Evaluates the table's multilinear extension at `point`.
+    fn evaluate_mle(&self, point: &[F]) -> F;
+    /// Identifies this subtable's type (e.g. `"EQ"`, `"LT"`), shared by every
+    /// instance regardless of which instruction constructed it.
+    fn subtable_id(&self) -> &'static str;
+}
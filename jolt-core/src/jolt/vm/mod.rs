@@ -0,0 +1 @@
+pub mod batched_lookups;
@@ -60,6 +60,15 @@ impl<F: PrimeField> InstructionStrategy<F> for LTInstruction<F> {
 pub struct LTSubtable<F: PrimeField> {
     _marker: PhantomData<F>
 }
+
+impl<F: PrimeField> LTSubtable<F> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<F: PrimeField> SubtableStrategy<F> for LTSubtable<F> {
   fn dimensions(&self) -> usize {
     8
@@ -102,6 +111,15 @@ impl<F: PrimeField> SubtableStrategy<F> for LTSubtable<F> {
 pub struct EQSubtable<F: PrimeField> {
     _marker: PhantomData<F>
 }
+
+impl<F: PrimeField> EQSubtable<F> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<F: PrimeField> SubtableStrategy<F> for EQSubtable<F> {
   fn dimensions(&self) -> usize {
     8
@@ -139,21 +157,95 @@ impl<F: PrimeField> SubtableStrategy<F> for EQSubtable<F> {
   }
 }
 
+/// A `DecomposableTable` adapter over the `LT`/`EQ` subtable pair, exposing
+/// the same Lasso decomposition as `LTVM` through the generic trait-object
+/// path so it can be proven with `lasso::table::prove`/`verify` instead of
+/// going through `InstructionStrategy`.
+pub struct LTTable<F: PrimeField> {
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> LTTable<F> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField> crate::lasso::table::DecomposableTable<F> for LTTable<F> {
+    fn num_chunks(&self) -> usize {
+        LTSubtable::<F> {
+            _marker: PhantomData,
+        }
+        .dimensions()
+    }
+
+    fn memory_size(&self) -> usize {
+        LTSubtable::<F> {
+            _marker: PhantomData,
+        }
+        .memory_size()
+    }
+
+    fn subtable_mles(
+        &self,
+    ) -> Vec<(
+        crate::lasso::table::SubtableMaterialize<F>,
+        crate::lasso::table::SubtableMLE<F>,
+    )> {
+        let new_lt = || LTSubtable::<F> {
+            _marker: PhantomData,
+        };
+        let new_eq = || EQSubtable::<F> {
+            _marker: PhantomData,
+        };
+        vec![
+            (
+                Box::new(move || new_lt().materialize()) as crate::lasso::table::SubtableMaterialize<F>,
+                Box::new(move |point: &[F]| new_lt().evaluate_mle(point)) as crate::lasso::table::SubtableMLE<F>,
+            ),
+            (
+                Box::new(move || new_eq().materialize()),
+                Box::new(move |point: &[F]| new_eq().evaluate_mle(point)),
+            ),
+        ]
+    }
+
+    fn combine(&self, vals: &[F]) -> F {
+        let c = self.num_chunks();
+        assert_eq!(vals.len(), 2 * c);
+        let mut sum = F::zero();
+        let mut eq_prod = F::one();
+        for i in 0..c {
+            sum += vals[2 * i] * eq_prod;
+            eq_prod *= vals[2 * i + 1];
+        }
+        sum
+    }
+
+    fn output_degree(&self) -> usize {
+        self.num_chunks()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-  use ark_curve25519::{EdwardsProjective, Fr};
+  use ark_ec::CurveGroup;
   use ark_ff::PrimeField;
   use ark_std::{log2, test_rng};
   use merlin::Transcript;
   use rand_chacha::rand_core::RngCore;
 
   use crate::{
-    jolt::lt::LTVM,
+    jolt::{
+      lt::{EQSubtable, LTSubtable, LTVM},
+      SubtableStrategy,
+    },
     lasso::{
-      densified::DensifiedRepresentation,
+      densified::{uniform_deref, DensifiedRepresentation},
       surge::{SparsePolyCommitmentGens, SparsePolynomialEvaluationProof},
     },
-    utils::random::RandomTape,
   };
 
   pub fn gen_indices<const C: usize>(sparsity: usize, memory_size: usize) -> Vec<Vec<usize>> {
@@ -175,8 +267,10 @@ mod tests {
     r_i
   }
 
-  #[test]
-  fn e2e() {
+  /// Runs the `LTVM`/`JoltStrategy` e2e proof+verify over whichever curve
+  /// `G` is chosen at the call site, so the prover isn't pinned to any one
+  /// curve at the type level.
+  fn e2e<F: PrimeField, G: CurveGroup<ScalarField = F>>() {
     const C: usize = 8;
     const S: usize = 1 << 8;
     const M: usize = 1 << 16;
@@ -185,26 +279,135 @@ mod tests {
     let log_s: usize = log2(S) as usize;
 
     let nz: Vec<Vec<usize>> = gen_indices::<C>(S, M);
-    let r: Vec<Fr> = gen_random_point::<Fr>(log_s);
-
-    let mut dense: DensifiedRepresentation<Fr, LTVM> =
-      DensifiedRepresentation::from_lookup_indices(&nz, log_m);
-    let gens =
-      SparsePolyCommitmentGens::<EdwardsProjective>::new(b"gens_sparse_poly", C, S, C, log_m);
-    let commitment = dense.commit::<EdwardsProjective>(&gens);
-    let mut random_tape = RandomTape::new(b"proof");
+    let r: Vec<F> = gen_random_point::<F>(log_s);
+
+    let lt_table = LTSubtable::<F>::new().materialize();
+    let eq_table = EQSubtable::<F>::new().materialize();
+    let (deref, memory_chunk, memory_table) = uniform_deref(&nz, &[lt_table, eq_table], C);
+
+    let mut dense: DensifiedRepresentation<F, LTVM> = DensifiedRepresentation::from_lookup_indices(
+      &nz,
+      deref,
+      memory_chunk.clone(),
+      memory_table.clone(),
+      log_m,
+    );
+    let gens = SparsePolyCommitmentGens::<G>::new(b"gens_sparse_poly", C, S, C, log_m);
+    let commitment = dense.commit::<G>(&gens);
     let mut prover_transcript = Transcript::new(b"example");
-    let proof = SparsePolynomialEvaluationProof::<EdwardsProjective, LTVM>::prove(
-      &mut dense,
+    let proof = SparsePolynomialEvaluationProof::<G, LTVM>::prove(&mut dense, &r, &gens, &mut prover_transcript);
+
+    let mut verify_transcript = Transcript::new(b"example");
+    proof
+      .verify(&commitment, &r, &gens, &mut verify_transcript, &memory_chunk, &memory_table)
+      .expect("should verify");
+  }
+
+  #[test]
+  fn e2e_curve25519() {
+    e2e::<ark_curve25519::Fr, ark_curve25519::EdwardsProjective>();
+  }
+
+  #[test]
+  fn e2e_bn254() {
+    e2e::<ark_bn254::Fr, ark_bn254::G1Projective>();
+  }
+
+  /// Runs the standalone `DecomposableTable` e2e proof+verify, generic over
+  /// the target curve the same way `e2e` above is.
+  fn e2e_decomposable_table<F: PrimeField, G: CurveGroup<ScalarField = F>>() {
+    use crate::lasso::table::{self, DecomposableTable};
+
+    const S: usize = 1 << 8;
+    const M: usize = 1 << 16;
+
+    let table = super::LTTable::<F>::new();
+    let log_m = log2(M) as usize;
+    let log_s = log2(S) as usize;
+
+    let nz: Vec<Vec<usize>> = gen_indices::<8>(S, M);
+    let r: Vec<F> = gen_random_point::<F>(log_s);
+
+    let gens = SparsePolyCommitmentGens::<G>::new(
+      b"gens_sparse_poly",
+      table.num_chunks(),
+      S,
+      table.num_chunks(),
+      log_m,
+    );
+    let mut prover_transcript = Transcript::new(b"example");
+    let proof = table::prove::<F, G>(&table, &nz, &r, &gens, &mut prover_transcript);
+
+    let mut verify_transcript = Transcript::new(b"example");
+    table::verify(
+      &table,
+      &proof,
       &r,
       &gens,
-      &mut prover_transcript,
-      &mut random_tape,
+      &mut verify_transcript,
+      proof.output,
+    )
+    .expect("should verify");
+  }
+
+  #[test]
+  fn e2e_decomposable_table_curve25519() {
+    e2e_decomposable_table::<ark_curve25519::Fr, ark_curve25519::EdwardsProjective>();
+  }
+
+  #[test]
+  fn e2e_decomposable_table_bn254() {
+    e2e_decomposable_table::<ark_bn254::Fr, ark_bn254::G1Projective>();
+  }
+
+  /// `e2e_decomposable_table` above only exercises a power-of-two `S`; this
+  /// runs the same proof+verify over a lookup count that isn't one, so
+  /// `DensifiedRepresentation::from_lookup_indices`'s padding is actually
+  /// on the hook (it used to panic here, in `InnerProductProof::prove`'s
+  /// `assert_eq!(a.len(), b.len())`).
+  fn e2e_decomposable_table_non_power_of_two<F: PrimeField, G: CurveGroup<ScalarField = F>>() {
+    use crate::lasso::densified::padded_num_ops;
+    use crate::lasso::table::{self, DecomposableTable};
+
+    const S: usize = 100;
+    const M: usize = 1 << 16;
+
+    let table = super::LTTable::<F>::new();
+    let log_m = log2(M) as usize;
+    let log_s = log2(padded_num_ops(S)) as usize;
+
+    let nz: Vec<Vec<usize>> = gen_indices::<8>(S, M);
+    let r: Vec<F> = gen_random_point::<F>(log_s);
+
+    let gens = SparsePolyCommitmentGens::<G>::new(
+      b"gens_sparse_poly",
+      table.num_chunks(),
+      padded_num_ops(S),
+      table.num_chunks(),
+      log_m,
     );
+    let mut prover_transcript = Transcript::new(b"example");
+    let proof = table::prove::<F, G>(&table, &nz, &r, &gens, &mut prover_transcript);
 
     let mut verify_transcript = Transcript::new(b"example");
-    proof
-      .verify(&commitment, &r, &gens, &mut verify_transcript)
-      .expect("should verify");
+    table::verify(
+      &table,
+      &proof,
+      &r,
+      &gens,
+      &mut verify_transcript,
+      proof.output,
+    )
+    .expect("should verify");
+  }
+
+  #[test]
+  fn e2e_decomposable_table_non_power_of_two_curve25519() {
+    e2e_decomposable_table_non_power_of_two::<ark_curve25519::Fr, ark_curve25519::EdwardsProjective>();
+  }
+
+  #[test]
+  fn e2e_decomposable_table_non_power_of_two_bn254() {
+    e2e_decomposable_table_non_power_of_two::<ark_bn254::Fr, ark_bn254::G1Projective>();
   }
 }
@@ -0,0 +1,42 @@
+use ark_ff::PrimeField;
+
+/// A single Lasso subtable: a materializable table of size `memory_size()`
+/// together with its multilinear extension.
+pub trait SubtableStrategy<F: PrimeField> {
+    /// Number of chunks (dimension `C`) this subtable is read into.
+    fn dimensions(&self) -> usize;
+    /// Size `M` of the table.
+    fn memory_size(&self) -> usize;
+    /// Materializes the table in counting order.
+    fn materialize(&self) -> Vec<F>;
+    /// Evaluates the table's multilinear extension at `point`.
+    fn evaluate_mle(&self, point: &[F]) -> F;
+}
+
+/// A single instruction: which subtables it reads from, and how to collate
+/// the resulting subtable entries into the instruction's output.
+pub trait InstructionStrategy<F: PrimeField> {
+    fn subtables(&self) -> Vec<Box<dyn SubtableStrategy<F>>>;
+    /// The collation polynomial `g` combining subtable entries into the
+    /// instruction's output.
+    fn combine_lookups(&self, vals: &[F]) -> F;
+    /// The degree of `g`.
+    fn g_poly_degree(&self) -> usize;
+    /// Total number of underlying subtable/dimension pairs (the memories Lasso
+    /// argues over).
+    fn num_memories(&self) -> usize {
+        self.subtables().iter().map(|s| s.dimensions()).sum()
+    }
+}
+
+/// The full set of instructions proven together in one Lasso instance.
+pub trait JoltStrategy<F: PrimeField> {
+    type Instruction;
+
+    fn instructions() -> Vec<Box<dyn InstructionStrategy<F>>>;
+    /// Degree of the combined primary sumcheck polynomial across all
+    /// instructions.
+    fn primary_poly_degree() -> usize;
+}
+
+pub mod lt;
@@ -0,0 +1,208 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+
+use crate::jolt::JoltStrategy;
+use crate::lasso::surge::{Commitment, SparsePolyCommitmentGens};
+use crate::utils::parallel::*;
+
+/// The dense, per-memory layout Lasso commits to: for each of the `C *
+/// num_subtables` memories, the `dim` and `read` polynomials derived from
+/// the sparse lookup indices, plus `deref` -- the subtable entry each
+/// lookup actually read from that memory. Unlike `dim`/`read` (which only
+/// encode *which* index was read), `deref` is what lets a collation check
+/// bind to the witness: its opened evaluation is the actual looked-up
+/// value, not a subtable MLE evaluated at a point anyone could recompute
+/// without the witness.
+///
+/// `final_cts`/`memory_chunk`/`memory_table` exist purely to let
+/// `surge::SparsePolynomialEvaluationProof` run a real memory-checking
+/// argument tying `deref` back to `dim` and the actual table contents
+/// (rather than `deref` being an arbitrary, unconstrained witness): for
+/// each `deref` column `k`, `memory_chunk[k]` is the `dim`/`read` column it
+/// shares an address space with, and `memory_table[k]` is the table
+/// `deref[k]` is claimed to be dereferencing into. `final_cts[i]` is the
+/// per-address touch count after every access to chunk `i`'s address space
+/// (the "final timestamp" half of the memory check).
+pub struct DensifiedRepresentation<F: PrimeField, S> {
+    pub dim: Vec<Vec<F>>,
+    pub read: Vec<Vec<F>>,
+    pub deref: Vec<Vec<F>>,
+    pub final_cts: Vec<Vec<F>>,
+    pub memory_chunk: Vec<usize>,
+    pub memory_table: Vec<Vec<F>>,
+    pub num_ops: usize,
+    pub log_m: usize,
+    _marker: PhantomData<S>,
+}
+
+impl<F: PrimeField, S: JoltStrategy<F>> DensifiedRepresentation<F, S> {
+    /// Builds the dense representation from `C`-dimensional lookup indices
+    /// (one `Vec<usize>` per lookup, each of length `C`), `deref` --
+    /// already-dereferenced per-memory columns the caller supplies (see
+    /// `uniform_deref` for the common case where every lookup reads the
+    /// same set of subtables at every chunk) -- and the `(memory_chunk,
+    /// memory_table)` pair `uniform_deref` returns alongside it, identifying
+    /// which `dim` column and which table each `deref` column belongs to.
+    ///
+    /// `indices` needn't already have a power-of-two length: every `dim`/
+    /// `read`/`deref` column is padded up to
+    /// `padded_num_ops(indices.len())` with dummy reads of address `0`
+    /// (counted in `final_cts`, which stays `M`-sized), since `InnerProductProof`
+    /// and `GrandProductProof` both require a power-of-two-length input.
+    /// Padding with repeated address-`0` reads is sound: the extra
+    /// `(addr=0, value=table[0], timestamp)` accesses are reflected
+    /// consistently across `dim`, `read`, `deref`, and `final_cts`, so the
+    /// `init*write == read*final` multiset identity still holds -- the
+    /// padding rows just add equal, self-consistent terms to both sides.
+    pub fn from_lookup_indices(
+        indices: &[Vec<usize>],
+        mut deref: Vec<Vec<F>>,
+        memory_chunk: Vec<usize>,
+        memory_table: Vec<Vec<F>>,
+        log_m: usize,
+    ) -> Self {
+        let num_ops = indices.len();
+        let padded_ops = padded_num_ops(num_ops);
+        let pad = padded_ops - num_ops;
+        let c = indices.first().map(|idx| idx.len()).unwrap_or(0);
+        let m = 1usize << log_m;
+
+        let mut dim = vec![Vec::with_capacity(padded_ops); c];
+        let mut read = vec![Vec::with_capacity(padded_ops); c];
+        let mut counts = vec![vec![0u64; m]; c];
+
+        for lookup in indices {
+            for (i, &idx) in lookup.iter().enumerate() {
+                dim[i].push(F::from(idx as u64));
+                // `read[i][j]` is the number of times `idx` had already been
+                // touched before this access -- the running read-timestamp.
+                read[i].push(F::from(counts[i][idx]));
+                counts[i][idx] += 1;
+            }
+        }
+
+        for col in 0..c {
+            for _ in 0..pad {
+                dim[col].push(F::zero());
+                read[col].push(F::from(counts[col][0]));
+                counts[col][0] += 1;
+            }
+        }
+
+        let final_cts: Vec<Vec<F>> = counts
+            .into_iter()
+            .map(|col| col.into_iter().map(F::from).collect())
+            .collect();
+
+        // `deref` was computed by the caller from the unpadded `indices`,
+        // so pad each column to match `dim`/`read`: every padding row
+        // dereferences address `0`, so the padding value is that memory's
+        // own `memory_table[k][0]`.
+        for (k, column) in deref.iter_mut().enumerate() {
+            let pad_value = memory_table[k].first().copied().unwrap_or(F::zero());
+            column.resize(padded_ops, pad_value);
+        }
+
+        Self {
+            dim,
+            read,
+            deref,
+            final_cts,
+            memory_chunk,
+            memory_table,
+            num_ops: padded_ops,
+            log_m,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Commits to every `dim`/`read`/`deref` polynomial via a multi-scalar
+    /// multiplication against `gens`, plus `final_cts` against `gens_mem`
+    /// (it's `M`-sized, one entry per address, not `num_ops`-sized).
+    pub fn commit<G: CurveGroup<ScalarField = F>>(
+        &self,
+        gens: &SparsePolyCommitmentGens<G>,
+    ) -> Commitment<G> {
+        let dim_commitment = self
+            .dim
+            .par_iter()
+            .map(|dim| msm::<G>(&gens.gens_dim[..dim.len()], dim))
+            .collect();
+
+        let read_commitment = self
+            .read
+            .par_iter()
+            .map(|read| msm::<G>(&gens.gens_dim[..read.len()], read))
+            .collect();
+
+        let deref_commitment = self
+            .deref
+            .par_iter()
+            .map(|d| msm::<G>(&gens.gens_dim[..d.len()], d))
+            .collect();
+
+        let final_cts_commitment = self
+            .final_cts
+            .par_iter()
+            .map(|cts| msm::<G>(&gens.gens_mem[..cts.len()], cts))
+            .collect();
+
+        Commitment {
+            dim_commitment,
+            read_commitment,
+            deref_commitment,
+            final_cts_commitment,
+        }
+    }
+}
+
+/// The row count `DensifiedRepresentation::from_lookup_indices` actually
+/// densifies `num_ops` lookups into, after padding up to the next power of
+/// two (required by `InnerProductProof`/`GrandProductProof`). Exposed so
+/// callers that pre-size commitment generators for a known lookup count
+/// (e.g. `wasm::prove`, which receives caller-supplied, possibly
+/// untrusted, generators) can validate capacity themselves instead of
+/// hitting a slice-index panic deep in `commit`/`open`. `0` lookups need no
+/// padding -- there's nothing to prove.
+pub fn padded_num_ops(num_ops: usize) -> usize {
+    if num_ops == 0 {
+        0
+    } else {
+        num_ops.next_power_of_two()
+    }
+}
+
+/// Builds `deref` columns for the common case where every one of the `c`
+/// dim positions reads the same `tables`, in the same order, at every
+/// lookup (e.g. `LTInstruction` and the `DecomposableTable` adapter both
+/// read their full subtable list at each chunk). Produces one column per
+/// `(chunk, table)` pair, chunk-major/table-minor -- the order `combine`/
+/// `combine_lookups` expect -- alongside the parallel `memory_chunk`
+/// (chunk index) and `memory_table` (table contents) each column reads
+/// from, for `SparsePolynomialEvaluationProof`'s memory-checking argument.
+pub fn uniform_deref<F: PrimeField>(
+    indices: &[Vec<usize>],
+    tables: &[Vec<F>],
+    c: usize,
+) -> (Vec<Vec<F>>, Vec<usize>, Vec<Vec<F>>) {
+    let mut deref = Vec::with_capacity(c * tables.len());
+    let mut memory_chunk = Vec::with_capacity(c * tables.len());
+    let mut memory_table = Vec::with_capacity(c * tables.len());
+
+    for i in 0..c {
+        for table in tables {
+            deref.push(
+                indices
+                    .iter()
+                    .map(|lookup| table[lookup[i]])
+                    .collect::<Vec<F>>(),
+            );
+            memory_chunk.push(i);
+            memory_table.push(table.clone());
+        }
+    }
+
+    (deref, memory_chunk, memory_table)
+}
@@ -0,0 +1,248 @@
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use merlin::Transcript;
+
+use crate::lasso::surge::ProofVerifyError;
+
+/// A GKR-style grand-product argument: proves that `product` is the product
+/// of a length-`2^k` vector, without revealing the vector, by recursing down
+/// a binary multiplication tree one layer at a time. Each layer's relation
+/// (`tree[d+1][j] = tree[d][j] * tree[d][half+j]`) is checked with a
+/// degree-3 sumcheck, which reduces a single evaluation claim about
+/// `tree[d+1]` to a single evaluation claim about `tree[d]`. After all `k`
+/// layers, the claim is about the original (length-`2^k`) vector at a random
+/// point -- exactly the kind of claim `surge::verify_opening` already knows
+/// how to check against a real vector commitment, so the leaves never need
+/// to be revealed or re-committed on their own.
+///
+/// This is the multiset-equality primitive the Lasso/Spice memory-checking
+/// argument is built on: feeding it a "fingerprint" vector over random
+/// `(tau, gamma)` challenges turns `product` into a randomized hash of the
+/// multiset, so that comparing two such products (e.g. read-set times
+/// final-set against init-set times write-set) checks multiset equality
+/// with soundness error `O(n / |F|)`.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct GrandProductProof<F: PrimeField> {
+    pub product: F,
+    layers: Vec<GrandProductLayerProof<F>>,
+}
+
+/// One layer of the reduction: the degree-3 sumcheck's per-round
+/// evaluations, plus the prover's claimed `(left, right)` evaluations of the
+/// layer below at the point the sumcheck reduced to.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+struct GrandProductLayerProof<F: PrimeField> {
+    round_evals: Vec<[F; 4]>,
+    final_left: F,
+    final_right: F,
+}
+
+impl<F: PrimeField> GrandProductProof<F> {
+    /// Builds the full multiplication tree over `values` (`2^k` leaves) and
+    /// proves each layer's relation top-down, binding every round to
+    /// `transcript`. Returns the proof alongside the final reduction point
+    /// (length `k`) -- the caller opens the *actual* committed vector at
+    /// this point (via `surge::verify_opening`) to complete the binding
+    /// this argument alone only reduces to a single evaluation claim about.
+    pub fn prove(values: &[F], transcript: &mut Transcript) -> (Self, Vec<F>) {
+        let n = values.len();
+        assert!(n.is_power_of_two() && n > 0, "grand product input must be a non-empty power of two");
+        let k = n.trailing_zeros() as usize;
+
+        let mut tree: Vec<Vec<F>> = Vec::with_capacity(k + 1);
+        tree.push(values.to_vec());
+        for _ in 0..k {
+            let prev = tree.last().unwrap();
+            let half = prev.len() / 2;
+            tree.push((0..half).map(|j| prev[j] * prev[half + j]).collect());
+        }
+        let product = tree[k][0];
+
+        let mut layers = Vec::with_capacity(k);
+        let mut point: Vec<F> = Vec::new();
+        let mut claim = product;
+        for round in 0..k {
+            let lower = &tree[k - round - 1];
+            let half = lower.len() / 2;
+            let (left, right) = (&lower[..half], &lower[half..]);
+
+            let (layer, challenges) = GrandProductLayerProof::prove(left, right, &point, claim, transcript);
+            let (final_left, final_right) = (layer.final_left, layer.final_right);
+
+            let rho: F = challenge_scalar(transcript, b"gp_combine_bit");
+            claim = final_left + rho * (final_right - final_left);
+            point = std::iter::once(rho).chain(challenges).collect();
+            layers.push(layer);
+        }
+
+        (Self { product, layers }, point)
+    }
+
+    /// Replays every layer's sumcheck, ending with an evaluation claim about
+    /// the original (leaf) vector: `(point, claim)` such that, if `values`
+    /// was the honestly-proven vector, `claim == MLE(values)(point)`.
+    pub fn verify(&self, transcript: &mut Transcript) -> Result<(Vec<F>, F), ProofVerifyError> {
+        let mut point: Vec<F> = Vec::new();
+        let mut claim = self.product;
+        for layer in &self.layers {
+            let challenges = layer.verify_rounds(&point, claim, transcript)?;
+            let rho: F = challenge_scalar(transcript, b"gp_combine_bit");
+            claim = layer.final_left + rho * (layer.final_right - layer.final_left);
+            point = std::iter::once(rho).chain(challenges).collect();
+        }
+        Ok((point, claim))
+    }
+}
+
+impl<F: PrimeField> GrandProductLayerProof<F> {
+    /// Runs the degree-3 sumcheck proving
+    /// `claim == sum_x eq(point, x) * left(x) * right(x)`, folding `left`,
+    /// `right`, and the `eq(point, .)` table by the round challenge each
+    /// round, Bulletproofs-IPA-style.
+    fn prove(
+        left: &[F],
+        right: &[F],
+        point: &[F],
+        claim: F,
+        transcript: &mut Transcript,
+    ) -> (Self, Vec<F>) {
+        let num_vars = point.len();
+        debug_assert_eq!(left.len(), 1usize << num_vars);
+        debug_assert_eq!(right.len(), 1usize << num_vars);
+        let _ = claim;
+
+        let mut a = left.to_vec();
+        let mut b = right.to_vec();
+        let mut eq = eq_table(point);
+        let mut round_evals = Vec::with_capacity(num_vars);
+        let mut challenges = Vec::with_capacity(num_vars);
+
+        for _ in 0..num_vars {
+            let half = a.len() / 2;
+            let mut evals = [F::zero(); 4];
+            for i in 0..half {
+                let (a0, a1) = (a[i], a[half + i]);
+                let (b0, b1) = (b[i], b[half + i]);
+                let (e0, e1) = (eq[i], eq[half + i]);
+                let (da, db, de) = (a1 - a0, b1 - b0, e1 - e0);
+                let (mut at, mut bt, mut et) = (a0, b0, e0);
+                for eval in evals.iter_mut() {
+                    *eval += at * bt * et;
+                    at += da;
+                    bt += db;
+                    et += de;
+                }
+            }
+
+            for e in evals.iter() {
+                transcript.append_message(b"gp_round_eval", e.to_string().as_bytes());
+            }
+            let r_i: F = challenge_scalar(transcript, b"gp_round_challenge");
+
+            a = (0..half).map(|i| a[i] + (a[half + i] - a[i]) * r_i).collect();
+            b = (0..half).map(|i| b[i] + (b[half + i] - b[i]) * r_i).collect();
+            eq = (0..half).map(|i| eq[i] + (eq[half + i] - eq[i]) * r_i).collect();
+
+            round_evals.push(evals);
+            challenges.push(r_i);
+        }
+
+        let proof = Self {
+            round_evals,
+            final_left: a[0],
+            final_right: b[0],
+        };
+        (proof, challenges)
+    }
+
+    /// Checks every round's sum against the running claim and re-derives its
+    /// challenge from `transcript`, then checks the final reduction against
+    /// `eq(point, challenges) * final_left * final_right` -- `eq` is public,
+    /// so the verifier computes it directly rather than trusting the prover.
+    fn verify_rounds(
+        &self,
+        point: &[F],
+        claim: F,
+        transcript: &mut Transcript,
+    ) -> Result<Vec<F>, ProofVerifyError> {
+        if self.round_evals.len() != point.len() {
+            return Err(ProofVerifyError(
+                "grand product layer round count mismatch".to_string(),
+            ));
+        }
+
+        let mut current = claim;
+        let mut challenges = Vec::with_capacity(point.len());
+        for evals in &self.round_evals {
+            if evals[0] + evals[1] != current {
+                return Err(ProofVerifyError(
+                    "grand product layer sumcheck round failed".to_string(),
+                ));
+            }
+            for e in evals.iter() {
+                transcript.append_message(b"gp_round_eval", e.to_string().as_bytes());
+            }
+            let r_i: F = challenge_scalar(transcript, b"gp_round_challenge");
+            current = interpolate_cubic(evals, r_i);
+            challenges.push(r_i);
+        }
+
+        let expected = eq_eval(point, &challenges) * self.final_left * self.final_right;
+        if current != expected {
+            return Err(ProofVerifyError(
+                "grand product layer final evaluation mismatch".to_string(),
+            ));
+        }
+        Ok(challenges)
+    }
+}
+
+/// The full `2^{point.len()}`-entry table of `eq(i, point)` weights, MSB
+/// first (the same convention `surge::eq_weights` uses).
+fn eq_table<F: PrimeField>(point: &[F]) -> Vec<F> {
+    let n = 1usize << point.len();
+    (0..n)
+        .map(|i| {
+            let mut weight = F::one();
+            for (bit, x) in point.iter().enumerate() {
+                let b = (i >> (point.len() - 1 - bit)) & 1;
+                weight *= if b == 1 { *x } else { F::one() - x };
+            }
+            weight
+        })
+        .collect()
+}
+
+/// Evaluates `eq(point, x) = prod_i (point_i * x_i + (1 - point_i)(1 - x_i))`
+/// directly, in `O(len)` rather than building the full `2^len` table --
+/// what the verifier uses, since it never needs the table away from `x`.
+pub(crate) fn eq_eval<F: PrimeField>(point: &[F], x: &[F]) -> F {
+    assert_eq!(point.len(), x.len());
+    point
+        .iter()
+        .zip(x.iter())
+        .map(|(p, xi)| *p * xi + (F::one() - p) * (F::one() - xi))
+        .product()
+}
+
+/// Lagrange-interpolates the degree-<=3 polynomial through `(0, evals[0]),
+/// ..., (3, evals[3])` and evaluates it at `x`.
+fn interpolate_cubic<F: PrimeField>(evals: &[F; 4], x: F) -> F {
+    let two = F::from(2u64);
+    let three = F::from(3u64);
+    let six_inv = F::from(6u64).inverse().expect("field has characteristic > 3");
+    let two_inv = two.inverse().unwrap();
+
+    let l0 = (x - F::one()) * (x - two) * (x - three) * (-six_inv);
+    let l1 = x * (x - two) * (x - three) * two_inv;
+    let l2 = x * (x - F::one()) * (x - three) * (-two_inv);
+    let l3 = x * (x - F::one()) * (x - two) * six_inv;
+
+    evals[0] * l0 + evals[1] * l1 + evals[2] * l2 + evals[3] * l3
+}
+
+fn challenge_scalar<F: PrimeField>(transcript: &mut Transcript, label: &'static [u8]) -> F {
+    let mut buf = [0u8; 64];
+    transcript.challenge_bytes(label, &mut buf);
+    F::from_le_bytes_mod_order(&buf)
+}
@@ -0,0 +1,187 @@
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use merlin::Transcript;
+
+use crate::lasso::surge::ProofVerifyError;
+
+/// A transparent inner-product argument compressing a dot-product opening
+/// from `O(n)` group elements down to `O(log n)`.
+///
+/// Proves knowledge of length-`n` vectors `a, b` such that
+/// `P = <a,G> + <b,H> + <a,b>*Q` for public bases `G, H, Q`, without
+/// revealing `a, b` beyond their final folded scalars.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct InnerProductProof<G: CurveGroup> {
+    l_vec: Vec<G>,
+    r_vec: Vec<G>,
+    a_final: G::ScalarField,
+    b_final: G::ScalarField,
+}
+
+impl<F: PrimeField, G: CurveGroup<ScalarField = F>> InnerProductProof<G> {
+    /// Runs `log n` halving rounds, folding `a, b` (and the verifier's `G, H`)
+    /// by a Fiat-Shamir challenge drawn from `transcript` each round.
+    pub fn prove(
+        bases_g: &[G::Affine],
+        bases_h: &[G::Affine],
+        q: G::Affine,
+        mut a: Vec<F>,
+        mut b: Vec<F>,
+        transcript: &mut Transcript,
+    ) -> Self {
+        assert_eq!(a.len(), b.len());
+        assert!(a.len().is_power_of_two());
+
+        let mut g: Vec<G::Affine> = bases_g.to_vec();
+        let mut h: Vec<G::Affine> = bases_h.to_vec();
+        let mut l_vec = Vec::new();
+        let mut r_vec = Vec::new();
+
+        while a.len() > 1 {
+            let n = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(n);
+            let (b_lo, b_hi) = b.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+            let (h_lo, h_hi) = h.split_at(n);
+
+            let c_l = inner_product(a_lo, b_hi);
+            let c_r = inner_product(a_hi, b_lo);
+
+            let l = msm::<G>(g_hi, a_lo) + msm::<G>(h_lo, b_hi) + q * c_l;
+            let r = msm::<G>(g_lo, a_hi) + msm::<G>(h_hi, b_lo) + q * c_r;
+
+            append_point(transcript, b"L", &l);
+            append_point(transcript, b"R", &r);
+            let x: F = challenge_scalar(transcript, b"x");
+            let x_inv = x.inverse().expect("challenge is never zero");
+
+            a = (0..n).map(|i| a_lo[i] * x + a_hi[i] * x_inv).collect();
+            b = (0..n).map(|i| b_lo[i] * x_inv + b_hi[i] * x).collect();
+            g = (0..n)
+                .map(|i| (g_lo[i] * x_inv + g_hi[i] * x).into_affine())
+                .collect();
+            h = (0..n)
+                .map(|i| (h_lo[i] * x + h_hi[i] * x_inv).into_affine())
+                .collect();
+
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+
+        Self {
+            l_vec,
+            r_vec,
+            a_final: a[0],
+            b_final: b[0],
+        }
+    }
+
+    /// Number of halving rounds this proof ran, i.e. `log2` of the vector
+    /// length it was proven over.
+    pub fn num_rounds(&self) -> usize {
+        self.l_vec.len()
+    }
+
+    /// Reconstructs the folded generators from the stored challenges and
+    /// checks the final relation `a_final*G' + b_final*H' + a_final*b_final*Q
+    /// == P + sum_i x_i^2 * L_i + x_i^-2 * R_i`.
+    pub fn verify(
+        &self,
+        bases_g: &[G::Affine],
+        bases_h: &[G::Affine],
+        q: G::Affine,
+        p: G,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofVerifyError> {
+        let n = bases_g.len();
+        if !n.is_power_of_two() || self.l_vec.len() != n.ilog2() as usize {
+            return Err(ProofVerifyError(
+                "inner product proof round count mismatch".to_string(),
+            ));
+        }
+
+        let challenges: Vec<F> = self
+            .l_vec
+            .iter()
+            .zip(self.r_vec.iter())
+            .map(|(l, r)| {
+                append_point(transcript, b"L", l);
+                append_point(transcript, b"R", r);
+                challenge_scalar(transcript, b"x")
+            })
+            .collect();
+
+        // The coefficient of base `i` in the fully-folded generator is the
+        // product of `x_j^{+-1}` over every round, chosen by the `j`-th bit
+        // of `i` (the standard Bulletproofs `s_i` trick).
+        let log_n = challenges.len();
+        let s = |i: usize| -> F {
+            challenges
+                .iter()
+                .enumerate()
+                .map(|(j, x)| {
+                    let bit = (i >> (log_n - 1 - j)) & 1;
+                    if bit == 1 {
+                        *x
+                    } else {
+                        x.inverse().expect("challenge is never zero")
+                    }
+                })
+                .product()
+        };
+        let s_vals: Vec<F> = (0..n).map(s).collect();
+        let s_inv_vals: Vec<F> = s_vals.iter().map(|x| x.inverse().unwrap()).collect();
+
+        let g_final = msm::<G>(bases_g, &s_vals);
+        let h_final = msm::<G>(bases_h, &s_inv_vals);
+
+        let folded_p: G = p
+            + self
+                .l_vec
+                .iter()
+                .zip(challenges.iter())
+                .map(|(l, x)| *l * x.square())
+                .sum::<G>()
+            + self
+                .r_vec
+                .iter()
+                .zip(challenges.iter())
+                .map(|(r, x)| *r * x.square().inverse().unwrap())
+                .sum::<G>();
+
+        let expected = g_final * self.a_final
+            + h_final * self.b_final
+            + q * (self.a_final * self.b_final);
+
+        if expected == folded_p {
+            Ok(())
+        } else {
+            Err(ProofVerifyError(
+                "inner product argument failed to verify".to_string(),
+            ))
+        }
+    }
+}
+
+fn inner_product<F: PrimeField>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * y).sum()
+}
+
+fn msm<G: CurveGroup>(bases: &[G::Affine], scalars: &[G::ScalarField]) -> G {
+    bases
+        .iter()
+        .zip(scalars.iter())
+        .map(|(base, scalar)| *base * scalar)
+        .sum()
+}
+
+fn append_point<G: CurveGroup>(transcript: &mut Transcript, label: &'static [u8], point: &G) {
+    transcript.append_message(label, point.to_string().as_bytes());
+}
+
+fn challenge_scalar<F: PrimeField>(transcript: &mut Transcript, label: &'static [u8]) -> F {
+    let mut buf = [0u8; 64];
+    transcript.challenge_bytes(label, &mut buf);
+    F::from_le_bytes_mod_order(&buf)
+}
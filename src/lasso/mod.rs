@@ -0,0 +1,5 @@
+pub mod densified;
+pub mod grandproduct;
+pub mod ipa;
+pub mod surge;
+pub mod table;
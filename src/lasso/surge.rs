@@ -0,0 +1,745 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::SeedableRng;
+use ark_std::UniformRand;
+use merlin::Transcript;
+
+use crate::jolt::JoltStrategy;
+use crate::lasso::densified::DensifiedRepresentation;
+use crate::lasso::grandproduct::GrandProductProof;
+use crate::lasso::ipa::InnerProductProof;
+
+/// Public parameters for committing to a `DensifiedRepresentation` and for
+/// the inner-product argument that opens `dim`/`read`/`deref`/`final_cts`
+/// evaluations.
+///
+/// `gens_dim` and `gens_eq` are each `S`-sized (`2n+1` bases total with
+/// `gens_q`, for `n = S`) so that a single `InnerProductProof` per memory
+/// compresses what would otherwise be an `O(S)`-sized opening down to
+/// `O(log S)`. `dim`, `read`, and `deref` are all `S`-sized, so they share
+/// `gens_dim`/`gens_eq`/`gens_q`. `gens_mem`/`gens_mem_eq` are `M`-sized,
+/// for opening `final_cts` (one entry per table address, not per lookup).
+///
+/// Every basis is drawn via [`UniformRand`] from an RNG seeded off the
+/// transcript, rather than by scalar-multiplying `G::generator()` by a
+/// transcript-derived scalar: the latter makes every basis's discrete log
+/// relative to `G::generator()` publicly computable (anyone can replay the
+/// same transcript challenge), which collapses the "commitment" to a known
+/// linear form and lets a cheating prover solve for alternate openings with
+/// the same commitment. Sampling curve points directly means nobody --
+/// including the prover -- knows the relative discrete logs.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct SparsePolyCommitmentGens<G: CurveGroup> {
+    pub gens_dim: Vec<G::Affine>,
+    pub gens_eq: Vec<G::Affine>,
+    pub gens_mem: Vec<G::Affine>,
+    pub gens_mem_eq: Vec<G::Affine>,
+    pub gens_q: G::Affine,
+}
+
+impl<G: CurveGroup> SparsePolyCommitmentGens<G> {
+    /// `c` is the dimension and `num_memories` the number of `(dim, read,
+    /// deref)` triples; neither affects sizing (every memory opens against
+    /// the same `S`-sized bases), but the parameters are kept so callers
+    /// don't need to change how they construct `gens`. `log_m` sizes
+    /// `gens_mem`/`gens_mem_eq`.
+    pub fn new(label: &'static [u8], c: usize, s: usize, num_memories: usize, log_m: usize) -> Self {
+        let mut transcript = Transcript::new(label);
+        let _ = c;
+        let _ = num_memories;
+
+        let gens_dim = Self::uniform_generators(&mut transcript, s);
+        let gens_eq = Self::uniform_generators(&mut transcript, s);
+        let gens_mem = Self::uniform_generators(&mut transcript, 1 << log_m);
+        let gens_mem_eq = Self::uniform_generators(&mut transcript, 1 << log_m);
+        let gens_q = Self::uniform_generators(&mut transcript, 1)[0];
+
+        Self {
+            gens_dim,
+            gens_eq,
+            gens_mem,
+            gens_mem_eq,
+            gens_q,
+        }
+    }
+
+    /// Samples `len` "nothing up my sleeve" bases: each is a fresh random
+    /// curve point drawn via `UniformRand`, from an RNG seeded off a
+    /// transcript challenge, so no party ever learns a basis's discrete log
+    /// relative to any other basis or to `G::generator()`.
+    fn uniform_generators(transcript: &mut Transcript, len: usize) -> Vec<G::Affine> {
+        (0..len)
+            .map(|i| {
+                let mut seed = [0u8; 32];
+                transcript.append_message(b"gen_index", &(i as u64).to_le_bytes());
+                transcript.challenge_bytes(b"gen_seed", &mut seed);
+                let mut rng = StdRng::from_seed(seed);
+                G::rand(&mut rng)
+            })
+            .map(|p| p.into_affine())
+            .collect()
+    }
+}
+
+/// Commitment to a `DensifiedRepresentation`: one group element per `dim`,
+/// `read`, and `deref` memory (against `gens_dim`), plus one per `final_cts`
+/// column (against `gens_mem`) -- the witness half of the memory-checking
+/// argument's final-set, needed so `final_cts` can be opened at a random
+/// point without being revealed.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: CurveGroup> {
+    pub dim_commitment: Vec<G>,
+    pub read_commitment: Vec<G>,
+    pub deref_commitment: Vec<G>,
+    pub final_cts_commitment: Vec<G>,
+}
+
+#[derive(Debug)]
+pub struct ProofVerifyError(pub String);
+
+/// Proof that `SparsePolynomialEvaluationProof::prove` produced a
+/// `DensifiedRepresentation` whose `deref` memories (a) collate (via
+/// `combine_lookups`) to the claimed output, and (b) actually dereference
+/// `dim` into the memory table each column is tagged with -- via
+/// `memory_check`, the standard Lasso/Spice read/write/final multiset
+/// argument. Without `memory_check`, `deref` would be an unconstrained
+/// witness: a prover could commit to any values at all and still pass the
+/// `dim`/`read`/`deref` openings and the `combine_lookups` check, since
+/// those alone never relate `deref[i]` to `table[dim[i]]`.
+///
+/// Each `dim`/`read`/`deref` evaluation is opened via an `InnerProductProof`
+/// rather than by revealing the underlying length-`S` vector, so the proof
+/// carries `2*log(S)` group elements (plus two scalars) per memory instead
+/// of `S` field elements. `eval_output` is checked against `combine_lookups`
+/// applied to `eval_deref` -- not a subtable MLE evaluated at a public
+/// point -- so `verify` is checking a value tied to `deref_commitment`
+/// rather than recomputing a function of `r` alone.
+///
+/// `S` needs `Send + Sync` only because `PhantomData<S>`'s derived
+/// `CanonicalSerialize`/`CanonicalDeserialize` impls require it (via
+/// `ark-serialize`'s blanket `Valid` impl for `PhantomData<T: Send + Sync>`)
+/// -- `S` never appears in an actual field.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct SparsePolynomialEvaluationProof<G: CurveGroup, S: Send + Sync> {
+    dim_ipa: Vec<InnerProductProof<G>>,
+    read_ipa: Vec<InnerProductProof<G>>,
+    deref_ipa: Vec<InnerProductProof<G>>,
+    eval_dim: Vec<G::ScalarField>,
+    eval_read: Vec<G::ScalarField>,
+    eval_deref: Vec<G::ScalarField>,
+    eval_output: G::ScalarField,
+    memory_check: MemoryCheckProof<G::ScalarField, G>,
+    _marker: PhantomData<S>,
+}
+
+impl<F: PrimeField, G: CurveGroup<ScalarField = F>, S: JoltStrategy<F> + Send + Sync>
+    SparsePolynomialEvaluationProof<G, S>
+{
+    /// Opens every memory polynomial of `dense` at `r`, records the combined
+    /// lookup output, and proves `memory_check` binding `deref` to `dim` and
+    /// `dense.memory_table` -- binding all of it to `transcript`.
+    pub fn prove(
+        dense: &mut DensifiedRepresentation<F, S>,
+        r: &[F],
+        gens: &SparsePolyCommitmentGens<G>,
+        transcript: &mut Transcript,
+    ) -> Self {
+        transcript.append_message(b"protocol", b"sparse_poly_eval");
+        for x in r {
+            transcript.append_message(b"r", &x.to_string().into_bytes());
+        }
+
+        let eq_r = eq_weights(r, dense.num_ops);
+
+        let (dim_ipa, eval_dim): (Vec<_>, Vec<_>) = dense
+            .dim
+            .iter()
+            .map(|d| open(d, &eq_r, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+        let (read_ipa, eval_read): (Vec<_>, Vec<_>) = dense
+            .read
+            .iter()
+            .map(|d| open(d, &eq_r, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+        let (deref_ipa, eval_deref): (Vec<_>, Vec<_>) = dense
+            .deref
+            .iter()
+            .map(|d| open(d, &eq_r, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+
+        let instructions = S::instructions();
+        let eval_output = instructions
+            .first()
+            .map(|instr| instr.combine_lookups(&eval_deref))
+            .unwrap_or(F::zero());
+
+        let memory_check = MemoryCheckProof::prove(
+            &dense.dim,
+            &dense.read,
+            &dense.deref,
+            &dense.final_cts,
+            &dense.memory_chunk,
+            &dense.memory_table,
+            dense.num_ops,
+            1 << dense.log_m,
+            gens,
+            transcript,
+        );
+
+        Self {
+            dim_ipa,
+            read_ipa,
+            deref_ipa,
+            eval_dim,
+            eval_read,
+            eval_deref,
+            eval_output,
+            memory_check,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The opened, commitment-bound evaluation of each `deref` memory, in
+    /// `combine`/`combine_lookups`'s expected order -- exposed so adapters
+    /// built on top of this proof (e.g. `lasso::table`) can recompute their
+    /// own collation from data this proof already checked, instead of a
+    /// value anyone could recompute from `r` alone.
+    pub fn eval_deref(&self) -> &[F] {
+        &self.eval_deref
+    }
+
+    /// Checks that the opened evaluations are consistent with `commitment`,
+    /// that collating `eval_deref` reproduces the claimed output, and that
+    /// `memory_check` proves every `deref` column was honestly dereferenced
+    /// from `dim` into `memory_table[k]` (`memory_chunk[k]` identifying
+    /// which `dim`/`read` column each `deref` column shares an address
+    /// space with) -- the same `(memory_chunk, memory_table)` pair
+    /// `uniform_deref`/`from_lookup_indices` produced when proving.
+    pub fn verify(
+        &self,
+        commitment: &Commitment<G>,
+        r: &[F],
+        gens: &SparsePolyCommitmentGens<G>,
+        transcript: &mut Transcript,
+        memory_chunk: &[usize],
+        memory_table: &[Vec<F>],
+    ) -> Result<(), ProofVerifyError> {
+        transcript.append_message(b"protocol", b"sparse_poly_eval");
+        for x in r {
+            transcript.append_message(b"r", &x.to_string().into_bytes());
+        }
+
+        if commitment.dim_commitment.len() != self.eval_dim.len()
+            || commitment.read_commitment.len() != self.eval_read.len()
+            || commitment.deref_commitment.len() != self.eval_deref.len()
+        {
+            return Err(ProofVerifyError(
+                "dim/read/deref commitment count mismatch".to_string(),
+            ));
+        }
+        if memory_chunk.len() != commitment.deref_commitment.len()
+            || memory_table.len() != commitment.deref_commitment.len()
+        {
+            return Err(ProofVerifyError(
+                "memory_chunk/memory_table count did not match the number of deref memories"
+                    .to_string(),
+            ));
+        }
+
+        let eq_r = eq_weights(r, gens.gens_dim.len());
+
+        for ((commit, ipa), eval) in commitment
+            .dim_commitment
+            .iter()
+            .zip(self.dim_ipa.iter())
+            .zip(self.eval_dim.iter())
+        {
+            verify_opening(*commit, ipa, &eq_r, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+        for ((commit, ipa), eval) in commitment
+            .read_commitment
+            .iter()
+            .zip(self.read_ipa.iter())
+            .zip(self.eval_read.iter())
+        {
+            verify_opening(*commit, ipa, &eq_r, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+        for ((commit, ipa), eval) in commitment
+            .deref_commitment
+            .iter()
+            .zip(self.deref_ipa.iter())
+            .zip(self.eval_deref.iter())
+        {
+            verify_opening(*commit, ipa, &eq_r, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+
+        // `self.eval_deref` was just checked above against
+        // `deref_commitment` via IPA, so it's bound to the prover's actual
+        // committed data rather than being a deterministic function of `r`
+        // alone -- collating it is a real check, not `f(r) == f(r)`.
+        let instructions = S::instructions();
+        let expected_output = instructions
+            .first()
+            .map(|instr| instr.combine_lookups(&self.eval_deref))
+            .unwrap_or(F::zero());
+
+        if expected_output != self.eval_output {
+            return Err(ProofVerifyError(
+                "collation polynomial did not match claimed output".to_string(),
+            ));
+        }
+
+        self.memory_check.verify(
+            commitment,
+            memory_chunk,
+            memory_table,
+            gens,
+            transcript,
+        )
+    }
+}
+
+/// The memory-checking argument binding `deref` to `dim` and the actual
+/// table contents, via the standard Lasso/Spice read/write/final multiset
+/// check: treating every access as a `(address, value, timestamp)` tuple,
+/// `init-set * write-set == read-set * final-set` as multisets, where
+/// `init-set` is every address paired with its real table value at
+/// timestamp `0` (fully public), `read-set`/`write-set` are what `dim`/
+/// `deref`/`read` claim was read/written at each access, and `final-set` is
+/// every address paired with its final touch count (`final_cts`). Tuples
+/// are tagged with their memory index `k` (via a `gamma^3 * k` term) so
+/// every memory's tuples can be checked as one combined multiset, instead
+/// of running the argument once per memory.
+///
+/// `write-set`'s tuples are `read-set`'s with `timestamp` incremented by
+/// one, so `read`'s opening at the write-set's reduction point still comes
+/// from the same committed `read` column -- no separate witness.
+/// `init-set`'s product is fully public (every tuple is `(addr,
+/// memory_table[k][addr], 0)`), so it's recomputed directly rather than
+/// proven.
+///
+/// Each multiset's product is proven via `GrandProductProof`, which reduces
+/// to a single evaluation claim about the combined fingerprint vector at a
+/// random point; that claim is then checked against the real `dim`/`read`/
+/// `deref`/`final_cts` commitments (via `open`/`verify_opening`) rather than
+/// trusted directly, which is what ties the multiset argument back to the
+/// actual committed witness instead of an independent, unconstrained one.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+struct MemoryCheckProof<F: PrimeField, G: CurveGroup<ScalarField = F>> {
+    read_product: GrandProductProof<F>,
+    write_product: GrandProductProof<F>,
+    final_product: GrandProductProof<F>,
+
+    read_dim_ipa: Vec<InnerProductProof<G>>,
+    read_dim_eval: Vec<F>,
+    read_read_ipa: Vec<InnerProductProof<G>>,
+    read_read_eval: Vec<F>,
+    read_deref_ipa: Vec<InnerProductProof<G>>,
+    read_deref_eval: Vec<F>,
+
+    write_dim_ipa: Vec<InnerProductProof<G>>,
+    write_dim_eval: Vec<F>,
+    write_read_ipa: Vec<InnerProductProof<G>>,
+    write_read_eval: Vec<F>,
+    write_deref_ipa: Vec<InnerProductProof<G>>,
+    write_deref_eval: Vec<F>,
+
+    final_cts_ipa: Vec<InnerProductProof<G>>,
+    final_cts_eval: Vec<F>,
+}
+
+impl<F: PrimeField, G: CurveGroup<ScalarField = F>> MemoryCheckProof<F, G> {
+    #[allow(clippy::too_many_arguments)]
+    fn prove(
+        dim: &[Vec<F>],
+        read: &[Vec<F>],
+        deref: &[Vec<F>],
+        final_cts: &[Vec<F>],
+        memory_chunk: &[usize],
+        memory_table: &[Vec<F>],
+        num_ops: usize,
+        m: usize,
+        gens: &SparsePolyCommitmentGens<G>,
+        transcript: &mut Transcript,
+    ) -> Self {
+        let num_memories = deref.len();
+        assert!(
+            num_memories.is_power_of_two(),
+            "memory-checking argument needs a power-of-two memory count"
+        );
+
+        transcript.append_message(b"protocol", b"memory_check");
+        let tau: F = challenge_scalar(transcript, b"tau");
+        let gamma: F = challenge_scalar(transcript, b"gamma");
+        let gamma2 = gamma * gamma;
+        let gamma3 = gamma2 * gamma;
+
+        let mut read_fp = Vec::with_capacity(num_memories * num_ops);
+        let mut write_fp = Vec::with_capacity(num_memories * num_ops);
+        for k in 0..num_memories {
+            let chunk = memory_chunk[k];
+            let tag = gamma3 * F::from(k as u64);
+            for row in 0..num_ops {
+                let base = dim[chunk][row] + gamma * deref[k][row] - tau + tag;
+                read_fp.push(base + gamma2 * read[chunk][row]);
+                write_fp.push(base + gamma2 * (read[chunk][row] + F::one()));
+            }
+        }
+
+        let mut final_fp = Vec::with_capacity(num_memories * m);
+        for k in 0..num_memories {
+            let chunk = memory_chunk[k];
+            let tag = gamma3 * F::from(k as u64);
+            for addr in 0..m {
+                final_fp.push(
+                    F::from(addr as u64) + gamma * memory_table[k][addr] - tau + tag
+                        + gamma2 * final_cts[chunk][addr],
+                );
+            }
+        }
+
+        let (read_product, read_point) = GrandProductProof::prove(&read_fp, transcript);
+        let (write_product, write_point) = GrandProductProof::prove(&write_fp, transcript);
+        let (final_product, final_point) = GrandProductProof::prove(&final_fp, transcript);
+
+        let log_mem = num_memories.trailing_zeros() as usize;
+        let (_read_point_k, read_point_row) = read_point.split_at(log_mem);
+        let (_write_point_k, write_point_row) = write_point.split_at(log_mem);
+        let (_final_point_k, final_point_addr) = final_point.split_at(log_mem);
+
+        let eq_read_row = eq_weights(read_point_row, num_ops);
+        let (read_dim_ipa, read_dim_eval): (Vec<_>, Vec<_>) = dim
+            .iter()
+            .map(|d| open(d, &eq_read_row, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+        let (read_read_ipa, read_read_eval): (Vec<_>, Vec<_>) = read
+            .iter()
+            .map(|d| open(d, &eq_read_row, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+        let (read_deref_ipa, read_deref_eval): (Vec<_>, Vec<_>) = deref
+            .iter()
+            .map(|d| open(d, &eq_read_row, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+
+        let eq_write_row = eq_weights(write_point_row, num_ops);
+        let (write_dim_ipa, write_dim_eval): (Vec<_>, Vec<_>) = dim
+            .iter()
+            .map(|d| open(d, &eq_write_row, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+        let (write_read_ipa, write_read_eval): (Vec<_>, Vec<_>) = read
+            .iter()
+            .map(|d| open(d, &eq_write_row, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+        let (write_deref_ipa, write_deref_eval): (Vec<_>, Vec<_>) = deref
+            .iter()
+            .map(|d| open(d, &eq_write_row, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript))
+            .unzip();
+
+        let eq_final_addr = eq_weights(final_point_addr, m);
+        let (final_cts_ipa, final_cts_eval): (Vec<_>, Vec<_>) = final_cts
+            .iter()
+            .map(|cts| {
+                open(
+                    cts,
+                    &eq_final_addr,
+                    &gens.gens_mem,
+                    &gens.gens_mem_eq,
+                    gens.gens_q,
+                    transcript,
+                )
+            })
+            .unzip();
+
+        Self {
+            read_product,
+            write_product,
+            final_product,
+            read_dim_ipa,
+            read_dim_eval,
+            read_read_ipa,
+            read_read_eval,
+            read_deref_ipa,
+            read_deref_eval,
+            write_dim_ipa,
+            write_dim_eval,
+            write_read_ipa,
+            write_read_eval,
+            write_deref_ipa,
+            write_deref_eval,
+            final_cts_ipa,
+            final_cts_eval,
+        }
+    }
+
+    fn verify(
+        &self,
+        commitment: &Commitment<G>,
+        memory_chunk: &[usize],
+        memory_table: &[Vec<F>],
+        gens: &SparsePolyCommitmentGens<G>,
+        transcript: &mut Transcript,
+    ) -> Result<(), ProofVerifyError> {
+        let num_memories = commitment.deref_commitment.len();
+        if !num_memories.is_power_of_two() {
+            return Err(ProofVerifyError(
+                "memory-checking argument needs a power-of-two memory count".to_string(),
+            ));
+        }
+        let m = memory_table.first().map(|t| t.len()).unwrap_or(0);
+
+        transcript.append_message(b"protocol", b"memory_check");
+        let tau: F = challenge_scalar(transcript, b"tau");
+        let gamma: F = challenge_scalar(transcript, b"gamma");
+        let gamma2 = gamma * gamma;
+        let gamma3 = gamma2 * gamma;
+
+        let (read_point, read_claim) = self.read_product.verify(transcript)?;
+        let (write_point, write_claim) = self.write_product.verify(transcript)?;
+        let (final_point, final_claim) = self.final_product.verify(transcript)?;
+
+        let log_mem = num_memories.trailing_zeros() as usize;
+        let (read_point_k, read_point_row) = read_point.split_at(log_mem);
+        let (write_point_k, write_point_row) = write_point.split_at(log_mem);
+        let (final_point_k, final_point_addr) = final_point.split_at(log_mem);
+
+        let eq_read_row = eq_weights(read_point_row, 1 << read_point_row.len());
+        for ((commit, ipa), eval) in commitment
+            .dim_commitment
+            .iter()
+            .zip(self.read_dim_ipa.iter())
+            .zip(self.read_dim_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_read_row, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+        for ((commit, ipa), eval) in commitment
+            .read_commitment
+            .iter()
+            .zip(self.read_read_ipa.iter())
+            .zip(self.read_read_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_read_row, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+        for ((commit, ipa), eval) in commitment
+            .deref_commitment
+            .iter()
+            .zip(self.read_deref_ipa.iter())
+            .zip(self.read_deref_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_read_row, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+
+        let eq_write_row = eq_weights(write_point_row, 1 << write_point_row.len());
+        for ((commit, ipa), eval) in commitment
+            .dim_commitment
+            .iter()
+            .zip(self.write_dim_ipa.iter())
+            .zip(self.write_dim_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_write_row, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+        for ((commit, ipa), eval) in commitment
+            .read_commitment
+            .iter()
+            .zip(self.write_read_ipa.iter())
+            .zip(self.write_read_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_write_row, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+        for ((commit, ipa), eval) in commitment
+            .deref_commitment
+            .iter()
+            .zip(self.write_deref_ipa.iter())
+            .zip(self.write_deref_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_write_row, *eval, &gens.gens_dim, &gens.gens_eq, gens.gens_q, transcript)?;
+        }
+
+        let eq_final_addr = eq_weights(final_point_addr, 1 << final_point_addr.len());
+        for ((commit, ipa), eval) in commitment
+            .final_cts_commitment
+            .iter()
+            .zip(self.final_cts_ipa.iter())
+            .zip(self.final_cts_eval.iter())
+        {
+            verify_opening(*commit, ipa, &eq_final_addr, *eval, &gens.gens_mem, &gens.gens_mem_eq, gens.gens_q, transcript)?;
+        }
+
+        let num_chunks = commitment.dim_commitment.len();
+        if self.read_dim_eval.len() != num_chunks
+            || self.read_read_eval.len() != num_chunks
+            || self.write_dim_eval.len() != num_chunks
+            || self.write_read_eval.len() != num_chunks
+            || self.final_cts_eval.len() != num_chunks
+            || self.read_deref_eval.len() != memory_chunk.len()
+            || self.write_deref_eval.len() != memory_chunk.len()
+            || memory_chunk.iter().any(|&chunk| chunk >= num_chunks)
+        {
+            return Err(ProofVerifyError(
+                "memory check opening count did not match the committed chunks/memories".to_string(),
+            ));
+        }
+
+        let w_read_k = eq_weights(read_point_k, num_memories);
+        let mut expected_read_claim = F::zero();
+        for k in 0..num_memories {
+            let chunk = memory_chunk[k];
+            let tag = gamma3 * F::from(k as u64);
+            let fp = self.read_dim_eval[chunk] + gamma * self.read_deref_eval[k]
+                + gamma2 * self.read_read_eval[chunk]
+                - tau
+                + tag;
+            expected_read_claim += w_read_k[k] * fp;
+        }
+        if expected_read_claim != read_claim {
+            return Err(ProofVerifyError(
+                "read-set fingerprint did not match the grand product claim".to_string(),
+            ));
+        }
+
+        let w_write_k = eq_weights(write_point_k, num_memories);
+        let mut expected_write_claim = F::zero();
+        for k in 0..num_memories {
+            let chunk = memory_chunk[k];
+            let tag = gamma3 * F::from(k as u64);
+            let fp = self.write_dim_eval[chunk] + gamma * self.write_deref_eval[k]
+                + gamma2 * (self.write_read_eval[chunk] + F::one())
+                - tau
+                + tag;
+            expected_write_claim += w_write_k[k] * fp;
+        }
+        if expected_write_claim != write_claim {
+            return Err(ProofVerifyError(
+                "write-set fingerprint did not match the grand product claim".to_string(),
+            ));
+        }
+
+        let w_final_k = eq_weights(final_point_k, num_memories);
+        let addr_eval = index_mle(final_point_addr);
+        let mut expected_final_claim = F::zero();
+        for k in 0..num_memories {
+            let chunk = memory_chunk[k];
+            let tag = gamma3 * F::from(k as u64);
+            let table_eval = inner_product(&memory_table[k], &eq_final_addr);
+            let fp = addr_eval + gamma * table_eval - tau + tag + gamma2 * self.final_cts_eval[chunk];
+            expected_final_claim += w_final_k[k] * fp;
+        }
+        if expected_final_claim != final_claim {
+            return Err(ProofVerifyError(
+                "final-set fingerprint did not match the grand product claim".to_string(),
+            ));
+        }
+
+        // `init-set` is fully public (every `(addr, memory_table[k][addr],
+        // 0)` tuple), so its product is recomputed directly rather than
+        // proven.
+        let mut init_product = F::one();
+        for k in 0..num_memories {
+            let tag = gamma3 * F::from(k as u64);
+            for addr in 0..m {
+                init_product *= F::from(addr as u64) + gamma * memory_table[k][addr] - tau + tag;
+            }
+        }
+
+        if init_product * self.write_product.product != self.read_product.product * self.final_product.product {
+            return Err(ProofVerifyError(
+                "memory-check multiset identity (init*write == read*final) did not hold".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Opens `vals`' multilinear evaluation at the point implicit in `eq_r`
+/// (i.e. `<vals, eq_r>`) via an `InnerProductProof` against the given
+/// bases, rather than revealing `vals` directly.
+fn open<F: PrimeField, G: CurveGroup<ScalarField = F>>(
+    vals: &[F],
+    eq_r: &[F],
+    bases_g: &[G::Affine],
+    bases_h: &[G::Affine],
+    gens_q: G::Affine,
+    transcript: &mut Transcript,
+) -> (InnerProductProof<G>, F) {
+    let eval = inner_product(vals, eq_r);
+    let proof = InnerProductProof::prove(
+        &bases_g[..vals.len()],
+        &bases_h[..vals.len()],
+        gens_q,
+        vals.to_vec(),
+        eq_r.to_vec(),
+        transcript,
+    );
+    (proof, eval)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn verify_opening<F: PrimeField, G: CurveGroup<ScalarField = F>>(
+    commitment: G,
+    ipa: &InnerProductProof<G>,
+    eq_r: &[F],
+    eval: F,
+    bases_g: &[G::Affine],
+    bases_h: &[G::Affine],
+    gens_q: G::Affine,
+    transcript: &mut Transcript,
+) -> Result<(), ProofVerifyError> {
+    let n = 1usize << ipa.num_rounds();
+    let eq_r = &eq_r[..n];
+    let h_part: G = bases_h[..n]
+        .iter()
+        .zip(eq_r.iter())
+        .map(|(base, scalar)| *base * scalar)
+        .sum();
+    let p = commitment + h_part + gens_q * eval;
+    ipa.verify(&bases_g[..n], &bases_h[..n], gens_q, p, transcript)
+}
+
+fn inner_product<F: PrimeField>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * y).sum()
+}
+
+/// The multilinear extension of the "address" function (`i -> i`), in
+/// closed form: since `i = sum_bit bit_value * 2^bit_position` is already
+/// multilinear in its bits, its own unique multilinear extension is that
+/// same linear formula evaluated at `point` instead of at `{0,1}`-bits.
+/// `point` is MSB-first (matching `eq_weights`'s convention), so `point[0]`
+/// carries the highest weight.
+fn index_mle<F: PrimeField>(point: &[F]) -> F {
+    let mut result = F::zero();
+    let mut weight = F::one();
+    for x in point.iter().rev() {
+        result += weight * x;
+        weight = weight.double();
+    }
+    result
+}
+
+fn challenge_scalar<F: PrimeField>(transcript: &mut Transcript, label: &'static [u8]) -> F {
+    let mut buf = [0u8; 64];
+    transcript.challenge_bytes(label, &mut buf);
+    F::from_le_bytes_mod_order(&buf)
+}
+
+/// The `eq(i, point)` weights over the last `ceil(log2(len))` coordinates
+/// of `point` (all of `point` if it is shorter), MSB first: that is
+/// `len.next_power_of_two()` entries when `point` is long enough.
+fn eq_weights<F: PrimeField>(point: &[F], len: usize) -> Vec<F> {
+    let log_n = (usize::BITS - (len.max(1) - 1).leading_zeros()) as usize;
+    let point = &point[point.len() - log_n.min(point.len())..];
+    let n = 1usize << point.len();
+    (0..n)
+        .map(|i| {
+            let mut weight = F::one();
+            for (bit, x) in point.iter().enumerate() {
+                let b = (i >> (point.len() - 1 - bit)) & 1;
+                weight *= if b == 1 { *x } else { F::one() - x };
+            }
+            weight
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use merlin::Transcript;
+
+use crate::lasso::densified::{uniform_deref, DensifiedRepresentation};
+use crate::lasso::surge::{
+    Commitment, ProofVerifyError, SparsePolyCommitmentGens, SparsePolynomialEvaluationProof,
+};
+
+/// A subtable's materialization and multilinear extension, decoupled from
+/// any particular `InstructionStrategy`/`ELFInstruction`.
+pub type SubtableMaterialize<F> = Box<dyn Fn() -> Vec<F> + Sync>;
+pub type SubtableMLE<F> = Box<dyn Fn(&[F]) -> F + Sync>;
+
+/// The generic Lasso decomposition: a `C`-dimensional lookup into a set of
+/// subtables, collated by `combine`. Unlike `InstructionStrategy`, this has
+/// no dependency on `ELFInstruction` or any particular instruction set, so
+/// downstream users can prove lookups into arbitrary structured tables
+/// (range checks, bit-decompositions, S-boxes, ...) the same way they'd
+/// prove a RISC-V instruction's lookup.
+pub trait DecomposableTable<F: PrimeField>: Sync {
+    /// Dimension `C`: the number of chunks each lookup index is split into.
+    fn num_chunks(&self) -> usize;
+    /// Size `M` of each subtable.
+    fn memory_size(&self) -> usize;
+    /// One `(materialize, evaluate_mle)` pair per unique subtable type this
+    /// table reads from.
+    fn subtable_mles(&self) -> Vec<(SubtableMaterialize<F>, SubtableMLE<F>)>;
+    /// The collation polynomial combining subtable entries into the
+    /// table's output value. `vals` is ordered chunk-major, subtable-minor:
+    /// `[T1(chunk_0), T2(chunk_0), ..., T1(chunk_{C-1}), T2(chunk_{C-1})]`.
+    fn combine(&self, vals: &[F]) -> F;
+    /// Degree of `combine`.
+    fn output_degree(&self) -> usize;
+}
+
+/// Standalone proof that a vector of `indices` lookups into a
+/// `DecomposableTable` combine to the claimed output, with no
+/// `JoltInstructionSet`/`ELFInstruction` dependency.
+///
+/// `output` is the value `prove` computed by collating its own opened
+/// `deref` evaluations; it's informational for callers deciding what to
+/// pass `verify` as `claimed_output` (e.g. a test asserting round-trip
+/// consistency). `verify` never trusts it directly -- it recomputes
+/// `table.combine` from `proof.inner`'s opened (and commitment-checked)
+/// evaluations and checks *that* against `claimed_output`.
+pub struct LookupProof<G: CurveGroup> {
+    inner: SparsePolynomialEvaluationProof<G, AdHocStrategy>,
+    commitment: Commitment<G>,
+    pub output: G::ScalarField,
+}
+
+/// Proves every lookup in `indices` (one `Vec<usize>` of length
+/// `table.num_chunks()` per lookup) against `table`, opening the resulting
+/// dense representation at `r`. `indices` doesn't need a power-of-two
+/// length -- `DensifiedRepresentation::from_lookup_indices` pads it up to
+/// one internally -- but `gens` must have been sized (via
+/// `SparsePolyCommitmentGens::new`'s `s`) for at least
+/// `crate::lasso::densified::padded_num_ops(indices.len())`.
+pub fn prove<F: PrimeField, G: CurveGroup<ScalarField = F>>(
+    table: &dyn DecomposableTable<F>,
+    indices: &[Vec<usize>],
+    r: &[F],
+    gens: &SparsePolyCommitmentGens<G>,
+    transcript: &mut Transcript,
+) -> LookupProof<G> {
+    let log_m = ark_std::log2(table.memory_size()) as usize;
+    let tables: Vec<Vec<F>> = table
+        .subtable_mles()
+        .iter()
+        .map(|(materialize, _)| materialize())
+        .collect();
+    let (deref, memory_chunk, memory_table) = uniform_deref(indices, &tables, table.num_chunks());
+
+    let mut dense: DensifiedRepresentation<F, AdHocStrategy> =
+        DensifiedRepresentation::from_lookup_indices(indices, deref, memory_chunk, memory_table, log_m);
+    let commitment = dense.commit(gens);
+
+    let inner = SparsePolynomialEvaluationProof::<G, AdHocStrategy>::prove(&mut dense, r, gens, transcript);
+
+    let output = table.combine(inner.eval_deref());
+
+    LookupProof {
+        inner,
+        commitment,
+        output,
+    }
+}
+
+/// Verifies a `LookupProof` against `table`'s collation polynomial and the
+/// caller's `claimed_output`. Recomputes `table.combine` from `proof.inner`'s
+/// opened `deref` evaluations -- already checked against `proof.commitment`
+/// by `inner.verify` -- rather than trusting `proof.output`.
+pub fn verify<F: PrimeField, G: CurveGroup<ScalarField = F>>(
+    table: &dyn DecomposableTable<F>,
+    proof: &LookupProof<G>,
+    r: &[F],
+    gens: &SparsePolyCommitmentGens<G>,
+    transcript: &mut Transcript,
+    claimed_output: F,
+) -> Result<(), ProofVerifyError> {
+    let tables: Vec<Vec<F>> = table
+        .subtable_mles()
+        .iter()
+        .map(|(materialize, _)| materialize())
+        .collect();
+    let (_, memory_chunk, memory_table) = uniform_deref(&[], &tables, table.num_chunks());
+
+    proof
+        .inner
+        .verify(&proof.commitment, r, gens, transcript, &memory_chunk, &memory_table)?;
+
+    let expected_output = table.combine(proof.inner.eval_deref());
+    if expected_output != claimed_output {
+        return Err(ProofVerifyError(
+            "decomposable table lookup did not combine to the claimed output".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// A minimal `JoltStrategy` used only to thread a standalone
+/// `DecomposableTable` lookup through the existing dense/Surge machinery,
+/// without requiring callers to define their own `InstructionStrategy`.
+pub struct AdHocStrategy;
+
+impl<F: PrimeField> crate::jolt::JoltStrategy<F> for AdHocStrategy {
+    type Instruction = ();
+
+    fn instructions() -> Vec<Box<dyn crate::jolt::InstructionStrategy<F>>> {
+        vec![]
+    }
+
+    fn primary_poly_degree() -> usize {
+        1
+    }
+}
@@ -0,0 +1,8 @@
+#![allow(non_snake_case)]
+
+pub mod jolt;
+pub mod lasso;
+pub mod utils;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
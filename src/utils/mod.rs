@@ -0,0 +1,11 @@
+pub mod parallel;
+
+/// Splits a `2 * num_bits`-wide operand into its high and low halves.
+///
+/// Used to decompose a flattened lookup index into the `(lhs, rhs)` operands
+/// that a binary subtable (e.g. `LT`, `EQ`) was materialized over.
+pub fn split_bits(operand: usize, num_bits: usize) -> (usize, usize) {
+    let lhs = operand >> num_bits;
+    let rhs = operand & ((1 << num_bits) - 1);
+    (lhs, rhs)
+}
@@ -0,0 +1,153 @@
+//! Parallel helpers shared by the commitment and proving paths.
+//!
+//! Kept as a thin wrapper over `rayon` so call sites don't need to depend on
+//! it directly. There is no sequential fallback: `msm` always runs on rayon.
+
+pub use rayon::prelude::*;
+
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+
+/// Multi-scalar multiplication via the windowed Pippenger/bucket method,
+/// split across rayon threads.
+///
+/// Each scalar is partitioned into `w`-bit windows; within a window, bases
+/// are accumulated into `2^w - 1` buckets keyed by the scalar's digit for
+/// that window, and each window is reduced with the standard running-sum
+/// trick (`O(2^w)` additions instead of a scalar mul per bucket). Windows
+/// are then combined with `w` doublings between them. `w` is chosen from
+/// `log2(n)`, as is standard for Pippenger's method.
+pub fn msm<G: CurveGroup>(bases: &[G::Affine], scalars: &[G::ScalarField]) -> G {
+    assert_eq!(bases.len(), scalars.len());
+    if bases.is_empty() {
+        return G::zero();
+    }
+
+    let w = window_size(bases.len());
+    let num_bits = G::ScalarField::MODULUS_BIT_SIZE as usize;
+    let num_windows = num_bits.div_ceil(w);
+
+    let scalar_digits: Vec<Vec<u64>> = scalars
+        .par_iter()
+        .map(|s| {
+            let repr = s.into_bigint();
+            (0..num_windows)
+                .map(|i| get_bits_at(&repr, i * w, w))
+                .collect()
+        })
+        .collect();
+
+    // Each window is reduced independently (in parallel), then the windows
+    // are recombined most-significant-first via `w` doublings apiece.
+    let window_sums: Vec<G> = (0..num_windows)
+        .into_par_iter()
+        .map(|window| bucket_reduce::<G>(bases, &scalar_digits, window, w))
+        .collect();
+
+    window_sums
+        .into_iter()
+        .rev()
+        .fold(G::zero(), |acc, window_sum| {
+            let mut acc = acc;
+            for _ in 0..w {
+                acc = acc.double();
+            }
+            acc + window_sum
+        })
+}
+
+/// Reduces a single window's buckets: accumulate each base into the bucket
+/// indexed by its window digit, then fold buckets high-to-low with a
+/// running sum so that bucket `k` is counted `k` times without `k` separate
+/// scalar multiplications.
+fn bucket_reduce<G: CurveGroup>(
+    bases: &[G::Affine],
+    scalar_digits: &[Vec<u64>],
+    window: usize,
+    w: usize,
+) -> G {
+    let num_buckets = (1usize << w) - 1;
+    let mut buckets = vec![G::zero(); num_buckets];
+
+    for (base, digits) in bases.iter().zip(scalar_digits.iter()) {
+        let digit = digits[window] as usize;
+        if digit > 0 {
+            buckets[digit - 1] += *base;
+        }
+    }
+
+    let mut running_sum = G::zero();
+    let mut window_sum = G::zero();
+    for bucket in buckets.into_iter().rev() {
+        running_sum += bucket;
+        window_sum += running_sum;
+    }
+    window_sum
+}
+
+/// Extracts `num_bits` bits starting at `offset` from `repr`, as a `u64`
+/// digit for one Pippenger window.
+fn get_bits_at<B: BigInteger>(repr: &B, offset: usize, num_bits: usize) -> u64 {
+    let mut digit = 0u64;
+    for i in 0..num_bits {
+        if repr.get_bit(offset + i) {
+            digit |= 1 << i;
+        }
+    }
+    digit
+}
+
+/// Picks the Pippenger window size from the number of bases, following the
+/// usual `w ~ log2(n)` rule of thumb, with a fixed `w = 3` below `n = 32`
+/// (which also keeps `w` nonzero for `n = 1`).
+fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        (n as f64).log2().ceil() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_curve25519::{EdwardsProjective, Fr};
+    use ark_ec::CurveGroup;
+    use ark_ff::UniformRand;
+    use ark_std::test_rng;
+
+    use super::msm;
+
+    /// The straight-line sum `msm` is meant to agree with: one scalar
+    /// multiplication per base, no bucketing.
+    fn naive_msm(bases: &[<EdwardsProjective as CurveGroup>::Affine], scalars: &[Fr]) -> EdwardsProjective {
+        bases
+            .iter()
+            .zip(scalars.iter())
+            .map(|(base, scalar)| *base * scalar)
+            .sum()
+    }
+
+    fn check(n: usize) {
+        let mut rng = test_rng();
+        let bases: Vec<_> = (0..n)
+            .map(|_| EdwardsProjective::rand(&mut rng).into_affine())
+            .collect();
+        let scalars: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+
+        assert_eq!(
+            msm::<EdwardsProjective>(&bases, &scalars),
+            naive_msm(&bases, &scalars),
+            "msm disagreed with the naive sum for n = {n}"
+        );
+    }
+
+    #[test]
+    fn agrees_with_naive_sum() {
+        // 0/1 edges, a handful of non-power-of-two sizes, and a couple of
+        // power-of-two sizes that land on either side of `window_size`'s
+        // `n < 32` threshold.
+        for n in [0, 1, 2, 3, 17, 31, 32, 33, 100, 257, 1000] {
+            check(n);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! In-browser proving/verification entry points for the `LTVM` Lasso
+//! instance, gated behind the `wasm` feature.
+//!
+//! `SparsePolyCommitmentGens` only depends on `(C, S, log_M)` and is
+//! expensive to regenerate, so callers derive it once with [`gen_params`]
+//! (offline, or hosted as a static blob) and pass the serialized bytes into
+//! [`prove`]/[`verify`] rather than rebuilding it on every call.
+#![cfg(feature = "wasm")]
+
+use ark_curve25519::{EdwardsProjective, Fr};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use merlin::Transcript;
+use wasm_bindgen::prelude::*;
+
+use crate::jolt::lt::{EQSubtable, LTSubtable, LTVM};
+use crate::jolt::SubtableStrategy;
+use crate::lasso::densified::{self, uniform_deref, DensifiedRepresentation};
+use crate::lasso::surge::{Commitment, SparsePolyCommitmentGens, SparsePolynomialEvaluationProof};
+
+/// Dimension and table size of the `LTVM` instance exposed over WASM.
+const C: usize = 8;
+const LOG_M: usize = 16;
+
+/// Derives `SparsePolyCommitmentGens` for a Lasso instance over `s` lookups
+/// and serializes it with `ark-serialize`, for hosting on a static server
+/// and passing into [`prove`]/[`verify`] as `gens_ser`.
+#[wasm_bindgen]
+pub fn gen_params(s: usize) -> Vec<u8> {
+    let gens = SparsePolyCommitmentGens::<EdwardsProjective>::new(b"gens_sparse_poly", C, s, C, LOG_M);
+    let mut bytes = Vec::new();
+    gens.serialize_compressed(&mut bytes)
+        .expect("gens serialization should not fail");
+    bytes
+}
+
+/// The `ProveOutput::commitment` bytes are opaque to the caller, but must be
+/// round-tripped back into [`verify`] unmodified -- `prove` never exposed a
+/// way to obtain them otherwise, which made the `verify` binding unusable.
+#[wasm_bindgen(getter_with_clone)]
+pub struct ProveOutput {
+    /// `CanonicalSerialize`d `SparsePolynomialEvaluationProof`.
+    pub proof: Vec<u8>,
+    /// `CanonicalSerialize`d `Commitment` to `indices_js`'s dense
+    /// representation, to be passed into [`verify`] as `commitment_js`.
+    pub commitment: Vec<u8>,
+}
+
+/// Checks `num_indices`'s padded lookup count against `gens_dim_len` (the
+/// capacity `gens_ser`'s `gens_dim` was actually sized for). Factored out
+/// of `prove` as pure arithmetic, with no `JsValue`/`wasm_bindgen`
+/// involved, so the untrusted-input rejection can be unit tested without a
+/// wasm harness.
+fn check_indices_capacity(num_indices: usize, gens_dim_len: usize) -> Result<(), String> {
+    let padded_len = densified::padded_num_ops(num_indices);
+    if padded_len > gens_dim_len {
+        return Err(format!(
+            "indices_js has {num_indices} lookups (padded to {padded_len}), but gens_ser's gens_dim only has {gens_dim_len} bases -- call gen_params with a larger s"
+        ));
+    }
+    Ok(())
+}
+
+/// Proves `indices_js` (a `Vec<Vec<usize>>` of length-`C` lookups) against
+/// `LTVM`, opening at `r_js` (a `CanonicalSerialize`d `Vec<Fr>`). `gens_ser`
+/// is a `CanonicalSerialize`d `SparsePolyCommitmentGens` produced by
+/// [`gen_params`]. Returns the proof bytes alongside the commitment bytes
+/// [`verify`] needs.
+#[wasm_bindgen]
+pub fn prove(indices_js: JsValue, r_js: &[u8], gens_ser: &[u8]) -> Result<ProveOutput, JsValue> {
+    let indices: Vec<Vec<usize>> = serde_wasm_bindgen::from_value(indices_js)?;
+    let r = Vec::<Fr>::deserialize_compressed(r_js)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let gens = SparsePolyCommitmentGens::<EdwardsProjective>::deserialize_compressed(gens_ser)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    // `indices_js` is untrusted browser input, so reject a lookup count
+    // `gens_ser` wasn't sized for up front -- `from_lookup_indices` pads
+    // `indices.len()` to `padded_num_ops`, and `commit`/`open` then slice
+    // `gens.gens_dim` to that padded length, which panics (crashing the
+    // wasm module) rather than failing gracefully if `gens_dim` is short.
+    check_indices_capacity(indices.len(), gens.gens_dim.len()).map_err(|e| JsValue::from_str(&e))?;
+
+    let lt_table = LTSubtable::<Fr>::new().materialize();
+    let eq_table = EQSubtable::<Fr>::new().materialize();
+    let (deref, memory_chunk, memory_table) = uniform_deref(&indices, &[lt_table, eq_table], C);
+
+    let mut dense: DensifiedRepresentation<Fr, LTVM> = DensifiedRepresentation::from_lookup_indices(
+        &indices,
+        deref,
+        memory_chunk,
+        memory_table,
+        LOG_M,
+    );
+    let commitment = dense.commit(&gens);
+    let mut transcript = Transcript::new(b"jolt_wasm");
+    let proof = SparsePolynomialEvaluationProof::<EdwardsProjective, LTVM>::prove(
+        &mut dense,
+        &r,
+        &gens,
+        &mut transcript,
+    );
+
+    let mut proof_bytes = Vec::new();
+    proof
+        .serialize_compressed(&mut proof_bytes)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mut commitment_bytes = Vec::new();
+    commitment
+        .serialize_compressed(&mut commitment_bytes)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(ProveOutput {
+        proof: proof_bytes,
+        commitment: commitment_bytes,
+    })
+}
+
+/// Verifies a proof produced by [`prove`] against a commitment, opening
+/// point, and the same `gens_ser` blob used to prove.
+#[wasm_bindgen]
+pub fn verify(
+    proof_js: &[u8],
+    commitment_js: &[u8],
+    r_js: &[u8],
+    gens_ser: &[u8],
+) -> Result<bool, JsValue> {
+    let r = Vec::<Fr>::deserialize_compressed(r_js)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let gens = SparsePolyCommitmentGens::<EdwardsProjective>::deserialize_compressed(gens_ser)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let proof =
+        SparsePolynomialEvaluationProof::<EdwardsProjective, LTVM>::deserialize_compressed(
+            proof_js,
+        )
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let commitment = Commitment::<EdwardsProjective>::deserialize_compressed(commitment_js)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let lt_table = LTSubtable::<Fr>::new().materialize();
+    let eq_table = EQSubtable::<Fr>::new().materialize();
+    let (_, memory_chunk, memory_table) = uniform_deref(&[], &[lt_table, eq_table], C);
+
+    let mut transcript = Transcript::new(b"jolt_wasm");
+    Ok(proof
+        .verify(&commitment, &r, &gens, &mut transcript, &memory_chunk, &memory_table)
+        .is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_indices_capacity;
+
+    #[test]
+    fn rejects_indices_padded_past_gens_capacity() {
+        // 100 lookups pad to 128, which doesn't fit in a 64-base gens_dim.
+        assert!(check_indices_capacity(100, 64).is_err());
+    }
+
+    #[test]
+    fn accepts_indices_within_gens_capacity() {
+        assert!(check_indices_capacity(100, 128).is_ok());
+    }
+
+    #[test]
+    fn accepts_already_power_of_two_indices_at_exact_capacity() {
+        assert!(check_indices_capacity(64, 64).is_ok());
+    }
+}